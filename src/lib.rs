@@ -9,46 +9,91 @@
 //! ## Encrypt
 //!
 //! ```
-//! let encrypted = playfair_rs::encrypt("playfair example", "hide the gold in the tree stump", 'x').unwrap();
+//! use playfair_rs::SquareMode;
+//! let encrypted = playfair_rs::encrypt("playfair example", "hide the gold in the tree stump", 'x', SquareMode::MergeIJ).unwrap();
 //! println!("{encrypted}"); // bmodzbxdnabekudmuixmmouvif
 //! ```
 //!
 //! ## Decrypt
 //!
 //! ```
-//! let decrypted = playfair_rs::decrypt("playfair example", "bmodzbxdnabekudmuixmmouvif").unwrap();
+//! use playfair_rs::SquareMode;
+//! let decrypted = playfair_rs::decrypt("playfair example", "bmodzbxdnabekudmuixmmouvif", SquareMode::MergeIJ).unwrap();
 //! println!("{decrypted}"); // hidethegoldinthetrexestump
 //! ```
 //! [Playfair cipher]: <https://en.wikipedia.org/wiki/Playfair_cipher>
 
-/// Generates a 5x5 key square from the given keyword.
+use std::io::{self, Read, Write};
+
+/// Policy for fitting the 26-letter alphabet into the 5x5 (25-cell) Playfair square.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SquareMode {
+    /// Merge `'j'` into `'i'` so both share a single cell. This is the classic convention.
+    MergeIJ,
+    /// Drop the given letter entirely, so it never appears in the square or in a normalized message.
+    ///
+    /// The letter must be an ASCII alphabetic character (case-insensitive, e.g. both `'q'` and
+    /// `'Q'` omit the same letter); see [`is_valid_square_mode`].
+    Omit(char),
+}
+
+/// Reports whether `mode` names a letter `generate_key` can actually omit, i.e. `Omit`'s
+/// character is an ASCII alphabetic letter. `MergeIJ` is always valid.
+fn is_valid_square_mode(mode: SquareMode) -> bool {
+    match mode {
+        SquareMode::MergeIJ => true,
+        SquareMode::Omit(c) => c.is_ascii_alphabetic(),
+    }
+}
+
+/// Normalizes a single character according to the square's merge/omit policy.
+///
+/// Returns `None` if the character should be dropped from the square and from any message.
+fn normalize_char(mut c: char, mode: SquareMode) -> Option<u8> {
+    match mode {
+        SquareMode::MergeIJ => {
+            if c == 'j' {
+                c = 'i';
+            }
+            Some(c as u8)
+        }
+        SquareMode::Omit(omitted) => {
+            if c == omitted.to_ascii_lowercase() {
+                None
+            } else {
+                Some(c as u8)
+            }
+        }
+    }
+}
+
+/// Generates a 5x5 key square from the given keyword and square mode.
 ///
 /// Returned key is:
 /// * 25 characters long.
 /// * Contains valid ASCII characters.
 /// * Contains no repeated characters.
-fn generate_key(keyword: &str) -> Vec<u8> {
+fn generate_key(keyword: &str, mode: SquareMode) -> Vec<u8> {
     let mut key = Vec::<u8>::with_capacity(25);
 
     // Inline function for adding a new character to the key.
-    let update_key = |mut c: char, key: &mut Vec<u8>| {
-        // Replace 'j' with 'i' to fit 5x5 square.
-        if c == 'j' {
-            c = 'i';
-        }
-
-        // Add a new character to the key.
-        // Each character should be unique in the key.
-        if !key.contains(&(c as u8)) {
-            key.push(c as u8);
+    let update_key = |c: char, key: &mut Vec<u8>| {
+        // Apply the merge/omit policy before adding the character.
+        if let Some(c) = normalize_char(c, mode) {
+            // Add a new character to the key.
+            // Each character should be unique in the key.
+            if !key.contains(&c) {
+                key.push(c);
+            }
         }
     };
 
-    // Iterate the characters in the keyword and update the key.
+    // Iterate the characters in the keyword and update the key. Non-ASCII letters are dropped,
+    // since `normalize_char` casts to `u8` and the key square only ever holds ASCII bytes.
     keyword
         .to_lowercase()
         .chars()
-        .filter(|c| c.is_alphabetic())
+        .filter(|c| c.is_ascii_alphabetic())
         .for_each(|c| {
             update_key(c, &mut key);
         });
@@ -64,68 +109,114 @@ fn generate_key(keyword: &str) -> Vec<u8> {
 /// # Example
 ///
 /// ```
-/// playfair_rs::encrypt("playfair example", "hide the gold in the tree stump", 'x');
+/// use playfair_rs::SquareMode;
+/// playfair_rs::encrypt("playfair example", "hide the gold in the tree stump", 'x', SquareMode::MergeIJ);
 /// ```
-pub fn encrypt(keyword: &str, plaintext: &str, pad: char) -> Option<String> {
+pub fn encrypt(keyword: &str, plaintext: &str, pad: char, mode: SquareMode) -> Option<String> {
+    if !is_valid_square_mode(mode) {
+        return None;
+    }
+
     // Generate a key.
-    let key = generate_key(keyword);
+    let key = generate_key(keyword, mode);
 
-    // Convert plaintext to lowercase and replace 'j' with 'i'.
-    // Removes non-ASCII characters.
+    // Convert plaintext to lowercase, apply the merge/omit policy and remove non-ASCII characters.
     let mut plaintext: Vec<u8> = plaintext
         .to_lowercase()
         .chars()
-        .filter(|c| c.is_alphabetic())
-        .map(|mut c| {
-            if c == 'j' {
-                c = 'i'
-            }
-            c as u8
-        })
+        .filter(|c| c.is_ascii_alphabetic())
+        .filter_map(|c| normalize_char(c, mode))
         .collect();
 
-    // Loop over the characters 2 at a time and check for duplicates.
-    (0..plaintext.len()).step_by(2).for_each(|i| {
-        if plaintext.get(i + 1) == Some(&plaintext[i]) {
-            // Insert `pad` to separate the duplicates.
-            plaintext.insert(i + 1, pad as u8);
-        }
-    });
+    // Split doubled letters and pad an odd-length message. A doubled pad letter itself (e.g. "xx"
+    // with pad 'x') can't be split by inserting another pad, so fall back to a second pad letter
+    // for that case.
+    let fallback_pad = pick_fallback_pad(&key, pad as u8);
+    insert_pad(&mut plaintext, pad as u8, fallback_pad);
+
+    // Iterate through the pairs and encipher.
+    let ciphertext = shift_digrams(&plaintext, &key, 1)?;
+
+    String::from_utf8(ciphertext).ok()
+}
+
+/// Splits doubled letters within a digram with `pad` and, if the result has an odd length,
+/// appends one more `pad` (or `fallback_pad`, if the last byte is itself `pad`) so it can be
+/// split evenly into digrams.
+///
+/// If the doubled letter being split is itself `pad`, `fallback_pad` is used for that insertion
+/// instead, since inserting `pad` between two `pad`s would not actually separate them.
+fn insert_pad(text: &mut Vec<u8>, pad: u8, fallback_pad: u8) {
+    split_doubles(text, pad, fallback_pad);
 
-    // Append a padding at the end if we have an odd length.
-    if plaintext.len() % 2 != 0 {
-        plaintext.push(pad as u8);
+    // Append a padding at the end if we have an odd length, falling back to `fallback_pad` if the
+    // lone leftover byte is itself `pad` (otherwise the two would form an unsplit digram).
+    if text.len() % 2 != 0 {
+        let filler = if text.last() == Some(&pad) { fallback_pad } else { pad };
+        text.push(filler);
     }
+}
 
-    // Iterate through the pairs and encipher.
-    let mut ciphertext = Vec::new();
-    for i in (0..plaintext.len()).step_by(2) {
+/// Splits doubled letters within a digram with `pad`, falling back to `fallback_pad` when the
+/// doubled letter is itself `pad`. Unlike [`insert_pad`], a trailing unpaired byte is left as-is.
+fn split_doubles(text: &mut Vec<u8>, pad: u8, fallback_pad: u8) {
+    // Walk the digrams 2 characters at a time, re-checking against the current (possibly just
+    // grown) length so a run of 3+ repeated letters gets every pair split, not just the ones at
+    // the positions that existed before any insertion.
+    let mut i = 0;
+    while i < text.len() {
+        if text.get(i + 1) == Some(&text[i]) {
+            // Insert `pad` to separate the duplicates, or `fallback_pad` if they are `pad`.
+            let filler = if text[i] == pad { fallback_pad } else { pad };
+            text.insert(i + 1, filler);
+        }
+        i += 2;
+    }
+}
+
+/// Picks a letter suitable as a fallback pad: one that is present in `key` and distinct from
+/// `pad`, preferring the classic choices `'q'` and `'z'`.
+fn pick_fallback_pad(key: &[u8], pad: u8) -> u8 {
+    [b'q', b'z']
+        .into_iter()
+        .chain(key.iter().copied())
+        .find(|&c| c != pad && key.contains(&c))
+        .expect("key square has at least two distinct letters")
+}
+
+/// Shifts every digram in `text` through the key square by `shift` steps (`1` to encipher, `-1`
+/// to decipher), and returns `None` if a character cannot be found in `key`.
+fn shift_digrams(text: &[u8], key: &[u8], shift: i32) -> Option<Vec<u8>> {
+    let shifted = |i: usize| -> usize { (i as i32 + shift).rem_euclid(5) as usize };
+
+    let mut out = Vec::with_capacity(text.len());
+    for pair in text.chunks_exact(2) {
         // Get the positions of the characters.
-        // Needed for performing the operations on swapping or incrementing x and y values.
-        let yx1 = key.iter().position(|&c| c == plaintext[i])?;
-        let yx2 = key.iter().position(|&c| c == plaintext[i + 1])?;
+        // Needed for performing the operations on swapping or shifting x and y values.
+        let yx1 = key.iter().position(|&c| c == pair[0])?;
+        let yx2 = key.iter().position(|&c| c == pair[1])?;
         let (y1, x1) = (yx1 / 5, yx1 % 5);
         let (y2, x2) = (yx2 / 5, yx2 % 5);
 
         if y1 != y2 && x1 != x2 {
             // They are in different rows and columns.
             // We swap the x values and keep the same y values.
-            ciphertext.push(key[y1 * 5 + x2]);
-            ciphertext.push(key[y2 * 5 + x1]);
+            out.push(key[y1 * 5 + x2]);
+            out.push(key[y2 * 5 + x1]);
         } else if y1 == y2 {
             // They are in the same row.
-            // We increment the x values by 1.
-            ciphertext.push(key[y1 * 5 + (x1 + 1) % 5]);
-            ciphertext.push(key[y2 * 5 + (x2 + 1) % 5]);
+            // We shift the x values by one step.
+            out.push(key[y1 * 5 + shifted(x1)]);
+            out.push(key[y2 * 5 + shifted(x2)]);
         } else if x1 == x2 {
             // They are in the same column.
-            // We increment the y values by 1.
-            ciphertext.push(key[(y1 + 1) % 5 * 5 + x1]);
-            ciphertext.push(key[(y2 + 1) % 5 * 5 + x2]);
+            // We shift the y values by one step.
+            out.push(key[shifted(y1) * 5 + x1]);
+            out.push(key[shifted(y2) * 5 + x2]);
         }
     }
 
-    String::from_utf8(ciphertext).ok()
+    Some(out)
 }
 
 /// Deciphers the given ciphertext using the Playfair cipher and returns the plaintext.
@@ -133,54 +224,397 @@ pub fn encrypt(keyword: &str, plaintext: &str, pad: char) -> Option<String> {
 /// # Example
 ///
 /// ```
-/// playfair_rs::decrypt("playfair example", "bmodzbxdnabekudmuixmmouvif").unwrap();
+/// use playfair_rs::SquareMode;
+/// playfair_rs::decrypt("playfair example", "bmodzbxdnabekudmuixmmouvif", SquareMode::MergeIJ).unwrap();
 /// ```
-pub fn decrypt(keyword: &str, ciphertext: &str) -> Option<String> {
-    // Ciphertext must have an even number of characters.
-    if ciphertext.len() % 2 != 0 {
+pub fn decrypt(keyword: &str, ciphertext: &str, mode: SquareMode) -> Option<String> {
+    if !is_valid_square_mode(mode) {
         return None;
     }
 
-    // Convert ciphertext to lowercase and remove non-ASCII characters.
+    // Convert ciphertext to lowercase, apply the merge/omit policy and remove non-ASCII characters.
     let ciphertext = ciphertext
         .to_lowercase()
         .chars()
-        .filter(|c| c.is_alphabetic())
-        .map(|c| c as u8)
+        .filter(|c| c.is_ascii_alphabetic())
+        .filter_map(|c| normalize_char(c, mode))
         .collect::<Vec<u8>>();
 
+    // Ciphertext must have an even number of characters.
+    if ciphertext.len() % 2 != 0 {
+        return None;
+    }
+
     // Generate the key.
-    let key = generate_key(keyword);
+    let key = generate_key(keyword, mode);
 
     // Iterate through the pairs and decipher.
-    let mut plaintext = Vec::new();
-    for i in (0..ciphertext.len()).step_by(2) {
-        // Get the positions of the characters.
-        // Needed for performing the operations on swapping or decrementing x and y values.
-        let yx1 = key.iter().position(|&c| c == ciphertext[i])?;
-        let yx2 = key.iter().position(|&c| c == ciphertext[i + 1])?;
-        let (y1, x1) = (yx1 / 5, yx1 % 5);
-        let (y2, x2) = (yx2 / 5, yx2 % 5);
+    let plaintext = shift_digrams(&ciphertext, &key, -1)?;
 
-        if y1 != y2 && x1 != x2 {
-            // They are in different rows and columns.
-            // We swap the x values and keep the same y values.
-            plaintext.push(key[y1 * 5 + x2]);
-            plaintext.push(key[y2 * 5 + x1]);
-        } else if y1 == y2 {
-            // They are in the same row.
-            // We decrement the x values by 1.
-            plaintext.push(key[y1 * 5 + (x1 + 5 - 1) % 5]);
-            plaintext.push(key[y2 * 5 + (x2 + 5 - 1) % 5]);
-        } else if x1 == x2 {
-            // They are in the same column.
-            // We decrement the y values by 1.
-            plaintext.push(key[(y1 + 5 - 1) % 5 * 5 + x1]);
-            plaintext.push(key[(y2 + 5 - 1) % 5 * 5 + x2]);
+    String::from_utf8(plaintext).ok()
+}
+
+/// Errors returned when building a [`Playfair`] cipher or using it to encrypt/decrypt a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayfairError {
+    /// The keyword contains no ASCII alphabetic characters, so no key square could be built.
+    EmptyKeyword,
+    /// The given character is not an alphabetic character.
+    NonAlphabetic(char),
+    /// The pad character does not appear in the generated key square, e.g. it was dropped by
+    /// `SquareMode::Omit`, or the fallback pad is missing from it or equals the pad.
+    PadCollision,
+    /// The normalized message has an odd number of characters and cannot be split into digrams.
+    OddLength,
+    /// A normalized character did not appear in the key square. This should not happen for
+    /// ordinary ASCII alphabetic input, since the key square is built to cover every character
+    /// `encrypt`/`decrypt` can normalize; it guards against that invariant being violated.
+    KeyMismatch,
+}
+
+impl std::fmt::Display for PlayfairError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyKeyword => write!(f, "keyword contains no ASCII alphabetic characters"),
+            Self::NonAlphabetic(c) => write!(f, "'{c}' is not an alphabetic character"),
+            Self::PadCollision => {
+                write!(f, "pad character does not appear in the key square or equals the other pad")
+            }
+            Self::OddLength => write!(f, "message has an odd number of characters after normalization"),
+            Self::KeyMismatch => write!(f, "normalized character not found in the key square"),
         }
     }
+}
 
-    String::from_utf8(plaintext).ok()
+impl std::error::Error for PlayfairError {}
+
+/// A Playfair cipher built once from a keyword, pad character and square mode.
+///
+/// Building a `Playfair` generates and caches the 5x5 key square, so `encrypt`/`decrypt` can be
+/// called repeatedly without regenerating it, and failures are reported as a [`PlayfairError`]
+/// instead of a bare `None`.
+///
+/// # Example
+///
+/// ```
+/// use playfair_rs::{Playfair, SquareMode};
+/// let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+/// let encrypted = cipher.encrypt("hide the gold in the tree stump").unwrap();
+/// assert_eq!(encrypted, "bmodzbxdnabekudmuixmmouvif");
+/// ```
+#[derive(Debug)]
+pub struct Playfair {
+    key: Vec<u8>,
+    pad: u8,
+    fallback_pad: u8,
+    mode: SquareMode,
+}
+
+impl Playfair {
+    /// Builds a new cipher from a keyword, pad/null character and square mode.
+    ///
+    /// A fallback pad letter is picked automatically for the case where a doubled letter to be
+    /// split is itself the pad (see [`Playfair::with_fallback_pad`] to choose it explicitly).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlayfairError::EmptyKeyword`] if `keyword` has no ASCII alphabetic characters,
+    /// [`PlayfairError::NonAlphabetic`] if `pad` is not alphabetic, or
+    /// [`PlayfairError::PadCollision`] if `pad` does not end up in the generated key square.
+    pub fn new(keyword: &str, pad: char, mode: SquareMode) -> Result<Self, PlayfairError> {
+        let (key, pad) = Self::build_key(keyword, pad, mode)?;
+        let fallback_pad = pick_fallback_pad(&key, pad);
+        Ok(Self { key, pad, fallback_pad, mode })
+    }
+
+    /// Builds a new cipher like [`Playfair::new`], but with an explicit fallback pad letter
+    /// instead of an automatically picked one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Playfair::new`] for `keyword`/`pad`, plus
+    /// [`PlayfairError::NonAlphabetic`] if `fallback_pad` is not alphabetic, or
+    /// [`PlayfairError::PadCollision`] if `fallback_pad` does not end up in the generated key
+    /// square, or collides with `pad`.
+    pub fn with_fallback_pad(
+        keyword: &str,
+        pad: char,
+        fallback_pad: char,
+        mode: SquareMode,
+    ) -> Result<Self, PlayfairError> {
+        let (key, pad) = Self::build_key(keyword, pad, mode)?;
+
+        if !fallback_pad.is_alphabetic() {
+            return Err(PlayfairError::NonAlphabetic(fallback_pad));
+        }
+        let fallback_pad = normalize_char(fallback_pad.to_ascii_lowercase(), mode)
+            .ok_or(PlayfairError::PadCollision)?;
+        if fallback_pad == pad || !key.contains(&fallback_pad) {
+            return Err(PlayfairError::PadCollision);
+        }
+
+        Ok(Self { key, pad, fallback_pad, mode })
+    }
+
+    /// Generates the key square for `keyword`/`mode` and normalizes/validates `pad` against it.
+    fn build_key(keyword: &str, pad: char, mode: SquareMode) -> Result<(Vec<u8>, u8), PlayfairError> {
+        if !keyword.chars().any(|c| c.is_ascii_alphabetic()) {
+            return Err(PlayfairError::EmptyKeyword);
+        }
+        if !pad.is_alphabetic() {
+            return Err(PlayfairError::NonAlphabetic(pad));
+        }
+        if let SquareMode::Omit(omitted) = mode {
+            if !is_valid_square_mode(mode) {
+                return Err(PlayfairError::NonAlphabetic(omitted));
+            }
+        }
+
+        let key = generate_key(keyword, mode);
+        let pad = normalize_char(pad.to_ascii_lowercase(), mode).ok_or(PlayfairError::PadCollision)?;
+        if !key.contains(&pad) {
+            return Err(PlayfairError::PadCollision);
+        }
+
+        Ok((key, pad))
+    }
+
+    /// Enciphers `plaintext` and returns the ciphertext.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlayfairError::KeyMismatch`] if a normalized character is missing from the key
+    /// square; this should not happen for ordinary input.
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, PlayfairError> {
+        let mut plaintext: Vec<u8> = plaintext
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .filter_map(|c| normalize_char(c, self.mode))
+            .collect();
+
+        insert_pad(&mut plaintext, self.pad, self.fallback_pad);
+
+        // Every normalized character is guaranteed to be in `self.key`, since both were
+        // normalized with the same `self.mode`.
+        let ciphertext = shift_digrams(&plaintext, &self.key, 1).ok_or(PlayfairError::KeyMismatch)?;
+
+        Ok(String::from_utf8(ciphertext).expect("key square only contains ASCII letters"))
+    }
+
+    /// Enciphers `plaintext` like [`Playfair::encrypt`], but formats the ciphertext as the
+    /// conventional uppercase, space-separated digraphs, e.g. `BM OD ZB XD NA`.
+    pub fn encrypt_digraphs(&self, plaintext: &str) -> Result<String, PlayfairError> {
+        self.encrypt(plaintext).map(|ciphertext| format_digraphs(&ciphertext))
+    }
+
+    /// Deciphers `ciphertext` and returns the (possibly padded) plaintext.
+    ///
+    /// Non-alphabetic characters, such as the spaces in digraph-formatted input (`BM OD ZB`),
+    /// are stripped before deciphering, so output from [`Playfair::encrypt_digraphs`] can be
+    /// fed back in directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlayfairError::OddLength`] if the normalized ciphertext has an odd length, or
+    /// [`PlayfairError::KeyMismatch`] if a normalized character is missing from the key square
+    /// (this should not happen for ordinary input).
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, PlayfairError> {
+        let ciphertext: Vec<u8> = ciphertext
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .filter_map(|c| normalize_char(c, self.mode))
+            .collect();
+
+        if ciphertext.len() % 2 != 0 {
+            return Err(PlayfairError::OddLength);
+        }
+
+        let plaintext = shift_digrams(&ciphertext, &self.key, -1).ok_or(PlayfairError::KeyMismatch)?;
+
+        Ok(String::from_utf8(plaintext).expect("key square only contains ASCII letters"))
+    }
+
+    /// Deciphers `ciphertext` like [`Playfair::decrypt`], then strips the padding `encrypt`
+    /// inserted, reconstructing the original message as closely as possible.
+    ///
+    /// A pad byte (either `pad` or the fallback pad used when a doubled letter was itself `pad`)
+    /// is dropped when it sits strictly between two equal letters (it was inserted to split a
+    /// doubled letter), and a trailing pad byte is dropped (it was the odd-length filler).
+    ///
+    /// This is inherently ambiguous: a pad letter that legitimately occurred in the source text
+    /// between two equal letters, or as its last letter, can be indistinguishable from one
+    /// `encrypt` inserted, and is then dropped the same way. Apart from such inputs, feeding
+    /// `encrypt`'s output back through this method reproduces the normalized input that was
+    /// originally encrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Playfair::decrypt`].
+    pub fn decrypt_clean(&self, ciphertext: &str) -> Result<String, PlayfairError> {
+        let mut plaintext = self.decrypt(ciphertext)?.into_bytes();
+
+        // Scan left to right, dropping any pad byte that was inserted to split a doubled letter.
+        let mut i = 0;
+        while i < plaintext.len() {
+            if i > 0
+                && i + 1 < plaintext.len()
+                && (plaintext[i] == self.pad || plaintext[i] == self.fallback_pad)
+                && plaintext[i - 1] == plaintext[i + 1]
+            {
+                plaintext.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        // Drop the odd-length filler, if any. It's usually `pad`, but `fallback_pad` if the
+        // leftover byte that needed filling was itself `pad` (see `insert_pad`).
+        if plaintext.last() == Some(&self.pad) || plaintext.last() == Some(&self.fallback_pad) {
+            plaintext.pop();
+        }
+
+        Ok(String::from_utf8(plaintext).expect("key square only contains ASCII letters"))
+    }
+}
+
+/// Formats a lowercase ciphertext/plaintext string as uppercase digraphs separated by spaces,
+/// e.g. `"bmodzb"` becomes `"BM OD ZB"`.
+fn format_digraphs(text: &str) -> String {
+    text.as_bytes()
+        .chunks(2)
+        .map(|digraph| {
+            std::str::from_utf8(digraph)
+                .expect("text only contains ASCII letters")
+                .to_uppercase()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Size of the read buffer used by [`encrypt_stream`] and [`decrypt_stream`].
+const STREAM_BUF_SIZE: usize = 8192;
+
+/// Normalizes a raw input byte according to the square mode `mode`.
+///
+/// Returns `None` if the byte is not an ASCII alphabetic character, or if it is dropped by the
+/// mode (e.g. `SquareMode::Omit`). Non-ASCII bytes are rejected outright rather than matched
+/// against `char::is_alphabetic`, since the key square only ever contains `'a'..='z'`.
+fn normalize_byte(byte: u8, mode: SquareMode) -> Option<u8> {
+    if !byte.is_ascii_alphabetic() {
+        return None;
+    }
+    normalize_char(byte.to_ascii_lowercase() as char, mode)
+}
+
+/// Enciphers `input` into `output` using `cipher`, without loading the whole input into memory.
+///
+/// Alphabetic bytes are normalized and buffered as they are read; complete digrams are enciphered
+/// and written out as soon as they accumulate, carrying over a leftover single byte (and any
+/// doubled-letter padding) across buffer boundaries. Any trailing odd byte is padded and flushed
+/// once `input` reaches EOF. The result is identical to calling [`Playfair::encrypt`] on the same
+/// normalized text.
+pub fn encrypt_stream<R: Read, W: Write>(
+    cipher: &Playfair,
+    mut input: R,
+    mut output: W,
+) -> io::Result<()> {
+    let mut buf = [0u8; STREAM_BUF_SIZE];
+    let mut carry: Option<u8> = None;
+
+    loop {
+        let n = input.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+
+        let mut chunk: Vec<u8> = carry
+            .take()
+            .into_iter()
+            .chain(
+                buf[..n]
+                    .iter()
+                    .filter_map(|&b| normalize_byte(b, cipher.mode)),
+            )
+            .collect();
+
+        // Split doubled letters (possibly spanning the previous chunk's carried-over byte), but
+        // leave a trailing unpaired byte as-is; it may yet double with the start of the next
+        // chunk, so only [`insert_pad`]'s final odd-length padding applies once at true EOF.
+        split_doubles(&mut chunk, cipher.pad, cipher.fallback_pad);
+        if chunk.len() % 2 != 0 {
+            carry = chunk.pop();
+        }
+
+        let ciphertext = shift_digrams(&chunk, &cipher.key, 1)
+            .expect("normalized bytes are always present in the key square");
+        output.write_all(&ciphertext)?;
+    }
+
+    if let Some(last) = carry {
+        // Fall back to `fallback_pad` if `last` is itself `pad`, matching `insert_pad`'s behavior.
+        let filler = if last == cipher.pad { cipher.fallback_pad } else { cipher.pad };
+        let ciphertext = shift_digrams(&[last, filler], &cipher.key, 1)
+            .expect("normalized bytes are always present in the key square");
+        output.write_all(&ciphertext)?;
+    }
+
+    Ok(())
+}
+
+/// Deciphers `input` into `output` using `cipher`, without loading the whole input into memory.
+///
+/// Alphabetic bytes are normalized and buffered as they are read; complete digrams are deciphered
+/// and written out as soon as they accumulate, carrying over a leftover single byte across buffer
+/// boundaries. The result is identical to calling [`Playfair::decrypt`] on the same normalized
+/// text.
+///
+/// # Errors
+///
+/// Returns an [`io::Error`] of kind [`io::ErrorKind::InvalidData`] if the normalized input has an
+/// odd number of characters.
+pub fn decrypt_stream<R: Read, W: Write>(
+    cipher: &Playfair,
+    mut input: R,
+    mut output: W,
+) -> io::Result<()> {
+    let mut buf = [0u8; STREAM_BUF_SIZE];
+    let mut carry: Option<u8> = None;
+
+    loop {
+        let n = input.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+
+        let mut chunk: Vec<u8> = carry
+            .take()
+            .into_iter()
+            .chain(
+                buf[..n]
+                    .iter()
+                    .filter_map(|&b| normalize_byte(b, cipher.mode)),
+            )
+            .collect();
+
+        if chunk.len() % 2 != 0 {
+            carry = chunk.pop();
+        }
+
+        let plaintext = shift_digrams(&chunk, &cipher.key, -1)
+            .expect("normalized bytes are always present in the key square");
+        output.write_all(&plaintext)?;
+    }
+
+    if carry.is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "ciphertext has an odd number of characters",
+        ));
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -198,10 +632,34 @@ mod tests {
             ("iabcdefghklmnopqrstuvwxyz", "iiiiiiiiiiiiiiiiiiiiiiiiiiiii"),
         ] {
             assert_eq!(25, key.len());
-            assert_eq!(key.as_bytes(), generate_key(keyword));
+            assert_eq!(key.as_bytes(), generate_key(keyword, SquareMode::MergeIJ));
         }
     }
 
+    #[test]
+    fn test_generate_key_omit() {
+        assert_eq!(25, generate_key("playfair example", SquareMode::Omit('q')).len());
+        assert!(!generate_key("playfair example", SquareMode::Omit('q')).contains(&b'q'));
+        assert_eq!(
+            b"playfirexmbcdghjknostuvwz".to_vec(),
+            generate_key("playfair example", SquareMode::Omit('q'))
+        );
+    }
+
+    #[test]
+    fn test_generate_key_omit_uppercase_letter() {
+        // `Omit('Q')` must omit the same letter as `Omit('q')`, not silently omit nothing.
+        assert_eq!(
+            generate_key("playfair example", SquareMode::Omit('q')),
+            generate_key("playfair example", SquareMode::Omit('Q'))
+        );
+    }
+
+    #[test]
+    fn test_encrypt_rejects_invalid_omit_mode() {
+        assert!(encrypt("playfair example", "za", 'x', SquareMode::Omit('1')).is_none());
+    }
+
     #[test]
     fn test_encrypt() {
         for (keyword, plaintext, encrypted) in &[
@@ -219,7 +677,10 @@ mod tests {
             ("secretj", "rust is awesomej", "tqesgiheceuhsa"),
             ("1t2Q4GOrzPE", "mgk", "wenu"),
         ] {
-            assert_eq!(*encrypted, encrypt(keyword, plaintext, 'x').unwrap());
+            assert_eq!(
+                *encrypted,
+                encrypt(keyword, plaintext, 'x', SquareMode::MergeIJ).unwrap()
+            );
         }
     }
 
@@ -240,8 +701,219 @@ mod tests {
             ("secretj", "rustisawesomei", "tqesgiheceuhsa"),
             ("1t2Q4GOrzPE", "mgkx", "wenu"),
         ] {
-            assert_eq!(*plaintext, decrypt(keyword, encrypted).unwrap());
+            assert_eq!(
+                *plaintext,
+                decrypt(keyword, encrypted, SquareMode::MergeIJ).unwrap()
+            );
         }
-        assert!(decrypt("playfair", "oddnumberofchar").is_none());
+        assert!(decrypt("playfair", "oddnumberofchar", SquareMode::MergeIJ).is_none());
+    }
+
+    #[test]
+    fn test_playfair_roundtrip() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let encrypted = cipher.encrypt("hide the gold in the tree stump").unwrap();
+        assert_eq!("bmodzbxdnabekudmuixmmouvif", encrypted);
+        assert_eq!(
+            "hidethegoldinthetrexestump",
+            cipher.decrypt(&encrypted).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_playfair_new_errors() {
+        assert_eq!(
+            PlayfairError::EmptyKeyword,
+            Playfair::new("123", 'x', SquareMode::MergeIJ).unwrap_err()
+        );
+        assert_eq!(
+            PlayfairError::NonAlphabetic('1'),
+            Playfair::new("playfair", '1', SquareMode::MergeIJ).unwrap_err()
+        );
+        assert_eq!(
+            PlayfairError::PadCollision,
+            Playfair::new("playfair", 'q', SquareMode::Omit('q')).unwrap_err()
+        );
+        assert_eq!(
+            PlayfairError::NonAlphabetic('1'),
+            Playfair::new("playfair", 'x', SquareMode::Omit('1')).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_playfair_new_omit_uppercase_letter() {
+        // `Omit('Q')` must build the same key square as `Omit('q')`, not an inconsistent 26-letter
+        // one that later panics in `shift_digrams`.
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::Omit('Q')).unwrap();
+        assert_eq!("vf", cipher.encrypt("za").unwrap());
+    }
+
+    #[test]
+    fn test_playfair_non_ascii_letters_are_dropped_not_a_panic() {
+        // Non-ASCII alphabetic characters (e.g. 'é') are not representable in the ASCII-only key
+        // square, so they are stripped like any other non-alphabetic character rather than
+        // panicking or corrupting the key.
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        assert_eq!("dlym", cipher.encrypt("café").unwrap());
+        assert_eq!(PlayfairError::OddLength, cipher.decrypt("aé").unwrap_err());
+
+        let keyword_cipher = Playfair::new("café", 'x', SquareMode::MergeIJ).unwrap();
+        assert_eq!(25, keyword_cipher.key.len());
+        assert!(keyword_cipher.encrypt("zebra").is_ok());
+    }
+
+    #[test]
+    fn test_playfair_encrypt_never_returns_odd_length() {
+        // `encrypt` pads odd-length input instead of rejecting it, so it never returns
+        // `PlayfairError::OddLength`.
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        assert_eq!("", cipher.encrypt("").unwrap());
+        assert_eq!("", cipher.encrypt("123").unwrap());
+    }
+
+    #[test]
+    fn test_playfair_encrypt_digraphs() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let formatted = cipher
+            .encrypt_digraphs("hide the gold in the tree stump")
+            .unwrap();
+        assert_eq!("BM OD ZB XD NA BE KU DM UI XM MO UV IF", formatted);
+        assert_eq!(
+            "hidethegoldinthetrexestump",
+            cipher.decrypt(&formatted).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_playfair_decrypt_clean() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let encrypted = cipher.encrypt("hide the gold in the tree stump").unwrap();
+        assert_eq!("hidethegoldinthetreestump", cipher.decrypt_clean(&encrypted).unwrap());
+
+        let cipher = Playfair::new("test", 'x', SquareMode::MergeIJ).unwrap();
+        let encrypted = cipher.encrypt("testing example").unwrap();
+        assert_eq!("testingexample", cipher.decrypt_clean(&encrypted).unwrap());
+    }
+
+    #[test]
+    fn test_playfair_pad_collision() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        // The doubled "xx" is split with a fallback pad instead of a degenerate second 'x'.
+        let encrypted = cipher.encrypt("foxxtrot").unwrap();
+        assert_eq!("foxxtrot", cipher.decrypt_clean(&encrypted).unwrap());
+    }
+
+    #[test]
+    fn test_playfair_with_fallback_pad() {
+        let cipher =
+            Playfair::with_fallback_pad("playfair example", 'x', 'z', SquareMode::MergeIJ)
+                .unwrap();
+        let encrypted = cipher.encrypt("foxxtrot").unwrap();
+        assert_eq!("foxxtrot", cipher.decrypt_clean(&encrypted).unwrap());
+
+        assert_eq!(
+            PlayfairError::PadCollision,
+            Playfair::with_fallback_pad("playfair example", 'x', 'x', SquareMode::MergeIJ)
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_playfair_trailing_pad_uses_fallback() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        // "fox" normalizes to an odd-length leftover "x" that itself equals `pad`; the trailing
+        // digram must be filled with `fallback_pad`, not a second `x` that never splits.
+        let encrypted = cipher.encrypt("fox").unwrap();
+        assert_ne!("mm", &encrypted[encrypted.len() - 2..]);
+        assert_eq!("fox", cipher.decrypt_clean(&encrypted).unwrap());
+    }
+
+    #[test]
+    fn test_playfair_decrypt_odd_length() {
+        let cipher = Playfair::new("playfair", 'x', SquareMode::MergeIJ).unwrap();
+        assert_eq!(
+            PlayfairError::OddLength,
+            cipher.decrypt("oddnumberofchar").unwrap_err()
+        );
+    }
+
+    /// Wraps a [`Read`] so every call only returns a single byte, to exercise the
+    /// carried-over-chunk-boundary logic in [`encrypt_stream`]/[`decrypt_stream`].
+    struct OneByteAtATime<R>(R);
+
+    impl<R: Read> Read for OneByteAtATime<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            self.0.read(&mut buf[..1])
+        }
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_stream_matches_string_api() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let plaintext = "hide the gold in the tree stump";
+
+        let mut encrypted = Vec::new();
+        encrypt_stream(&cipher, OneByteAtATime(plaintext.as_bytes()), &mut encrypted).unwrap();
+        let encrypted = String::from_utf8(encrypted).unwrap();
+        assert_eq!(cipher.encrypt(plaintext).unwrap(), encrypted);
+
+        let mut decrypted = Vec::new();
+        decrypt_stream(
+            &cipher,
+            OneByteAtATime(encrypted.as_bytes()),
+            &mut decrypted,
+        )
+        .unwrap();
+        assert_eq!(
+            cipher.decrypt(&encrypted).unwrap(),
+            String::from_utf8(decrypted).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encrypt_stream_doubled_pad_across_chunks() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let plaintext = "foxxtrot";
+
+        let mut encrypted = Vec::new();
+        encrypt_stream(&cipher, OneByteAtATime(plaintext.as_bytes()), &mut encrypted).unwrap();
+        assert_eq!(cipher.encrypt(plaintext).unwrap().into_bytes(), encrypted);
+    }
+
+    #[test]
+    fn test_encrypt_stream_trailing_pad_uses_fallback() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let plaintext = "fox";
+
+        let mut encrypted = Vec::new();
+        encrypt_stream(&cipher, OneByteAtATime(plaintext.as_bytes()), &mut encrypted).unwrap();
+        assert_eq!(cipher.encrypt(plaintext).unwrap().into_bytes(), encrypted);
+    }
+
+    #[test]
+    fn test_decrypt_stream_odd_length() {
+        let cipher = Playfair::new("playfair", 'x', SquareMode::MergeIJ).unwrap();
+        let mut decrypted = Vec::new();
+        let err = decrypt_stream(&cipher, "oddnumberofchar".as_bytes(), &mut decrypted).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[test]
+    fn test_encrypt_stream_matches_string_api_for_repeated_letters() {
+        let cipher = Playfair::new("playfair example", 'x', SquareMode::MergeIJ).unwrap();
+        let plaintext = "aaaa";
+
+        let mut encrypted = Vec::new();
+        encrypt_stream(&cipher, OneByteAtATime(plaintext.as_bytes()), &mut encrypted).unwrap();
+        assert_eq!(cipher.encrypt(plaintext).unwrap().into_bytes(), encrypted);
+    }
+
+    #[test]
+    fn test_normalize_byte_rejects_non_ascii() {
+        assert_eq!(None, normalize_byte(0xE9, SquareMode::MergeIJ));
+        assert_eq!(Some(b'a'), normalize_byte(b'A', SquareMode::MergeIJ));
     }
 }